@@ -0,0 +1,258 @@
+//! Async variant of [`Joker`](crate::Joker), gated behind the `async` cargo feature and backed
+//! by `reqwest` instead of blocking `ureq`.
+
+use crate::{
+    parse_joke_envelope, parse_joke_value, ApiErrorBody, BlacklistFlag, Category, Error, Joke,
+    JokeEnvelope, JokeType, Joker, Language, ResponseFormat, BASE_URL,
+};
+
+/// An async counterpart to [`Joker`](crate::Joker). The builder methods mirror [`Joker`]'s
+/// one-to-one and delegate to it, so `build_url` and all configuration stays in one place;
+/// only the transport underneath `get_joke`/`submit_joke` is different.
+#[derive(Debug, Clone)]
+pub struct AsyncJoker {
+    inner: Joker,
+}
+
+impl AsyncJoker {
+    /// Basic Usage:
+    ///
+    /// ```rust
+    /// use joketeller::asynchronous::AsyncJoker;
+    ///
+    /// let async_joker_client: AsyncJoker = AsyncJoker::new();
+    /// ```
+    pub fn new() -> AsyncJoker {
+        AsyncJoker { inner: Joker::new() }
+    }
+
+    /// See [`Joker::add_categories`](crate::Joker::add_categories).
+    pub fn add_categories(&mut self, categories: &mut Vec<Category>) -> &mut Self {
+        self.inner.add_categories(categories);
+
+        self
+    }
+
+    /// See [`Joker::set_language`](crate::Joker::set_language).
+    pub fn set_language(&mut self, language: Language) -> &mut Self {
+        self.inner.set_language(language);
+
+        self
+    }
+
+    /// See [`Joker::add_blacklist_flags`](crate::Joker::add_blacklist_flags).
+    pub fn add_blacklist_flags(&mut self, flags: &mut Vec<BlacklistFlag>) -> &mut Self {
+        self.inner.add_blacklist_flags(flags);
+
+        self
+    }
+
+    /// See [`Joker::set_format`](crate::Joker::set_format).
+    pub fn set_format(&mut self, format: ResponseFormat) -> &mut Self {
+        self.inner.set_format(format);
+
+        self
+    }
+
+    /// See [`Joker::set_joke_type`](crate::Joker::set_joke_type).
+    pub fn set_joke_type(&mut self, joketype: JokeType) -> &mut Self {
+        self.inner.set_joke_type(joketype);
+
+        self
+    }
+
+    /// See [`Joker::set_search_string`](crate::Joker::set_search_string).
+    pub fn set_search_string(&mut self, searchstring: &'static str) -> &mut Self {
+        self.inner.set_search_string(searchstring);
+
+        self
+    }
+
+    /// See [`Joker::set_id_range`](crate::Joker::set_id_range).
+    pub fn set_id_range(&mut self, start: u32, end: u32) -> &mut Self {
+        self.inner.set_id_range(start, end);
+
+        self
+    }
+
+    /// See [`Joker::set_amount`](crate::Joker::set_amount).
+    pub fn set_amount(&mut self, amount: u32) -> &mut Self {
+        self.inner.set_amount(amount);
+
+        self
+    }
+
+    /// See [`Joker::safe_mode`](crate::Joker::safe_mode).
+    pub fn safe_mode(&mut self, s: bool) -> &mut Self {
+        self.inner.safe_mode(s);
+
+        self
+    }
+
+    /// See [`Joker::set_authorization`](crate::Joker::set_authorization).
+    pub fn set_authorization(&mut self, authorization_key: &'static str) -> &mut Self {
+        self.inner.set_authorization(authorization_key);
+
+        self
+    }
+
+    /// See [`Joker::build_url`](crate::Joker::build_url); reuses the exact same logic, including
+    /// its pre-flight parameter validation.
+    pub fn build_url(&mut self) -> Result<String, Error> {
+        self.inner.build_url()
+    }
+
+    /// Async counterpart to [`Joker::get_joke_raw`](crate::Joker::get_joke_raw).
+    pub async fn get_joke_raw(&mut self) -> Result<String, Error> {
+        let url_string = self.inner.build_url()?;
+
+        let mut req = reqwest::Client::new().get(&url_string);
+
+        if let Some(key) = self.inner.authorization_key.as_ref() {
+            req = req.header("Authorization", key);
+        }
+
+        let response = req.send().await.map_err(|_| Error::Transport)?;
+        let code = response.status().as_u16();
+        let text = response.text().await.map_err(|_| Error::Transport)?;
+
+        if (200..300).contains(&code) {
+            Ok(text)
+        } else {
+            let body: ApiErrorBody = serde_json::from_str(&text).map_err(Error::Deserialize)?;
+
+            Err(Error::from((code, body)))
+        }
+    }
+
+    /// Async counterpart to [`Joker::get_joke`](crate::Joker::get_joke).
+    pub async fn get_joke(&mut self) -> Result<serde_json::Value, Error> {
+        let raw = self.get_joke_raw().await?;
+
+        parse_joke_value(&raw, self.inner.format)
+    }
+
+    /// Async counterpart to [`Joker::get_joke_typed`](crate::Joker::get_joke_typed).
+    pub async fn get_joke_typed(&mut self) -> Result<Vec<Joke>, Error> {
+        let raw = self.get_joke_raw().await?;
+
+        match parse_joke_envelope(&raw, self.inner.format)? {
+            JokeEnvelope::Batch { jokes } => Ok(jokes),
+            JokeEnvelope::Single(joke) => Ok(vec![joke]),
+        }
+    }
+
+    /// Async counterpart to [`Joker::submit_joke`](crate::Joker::submit_joke).
+    pub async fn submit_joke(json: serde_json::Value) -> Result<serde_json::Value, Error> {
+        let mut submission_url = BASE_URL.to_string();
+        submission_url.push_str("submit");
+
+        submit(&submission_url, json).await
+    }
+
+    /// Async counterpart to [`Joker::submit_joke_dryrun`](crate::Joker::submit_joke_dryrun).
+    pub async fn submit_joke_dryrun(json: serde_json::Value) -> Result<serde_json::Value, Error> {
+        let mut submission_url = BASE_URL.to_string();
+        submission_url.push_str("submit?dry-run");
+
+        submit(&submission_url, json).await
+    }
+}
+
+async fn submit(url: &str, json: serde_json::Value) -> Result<serde_json::Value, Error> {
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&json)
+        .send()
+        .await
+        .map_err(|_| Error::Transport)?;
+
+    let code = response.status().as_u16();
+    let text = response.text().await.map_err(|_| Error::Transport)?;
+
+    if (200..300).contains(&code) {
+        serde_json::from_str(&text).map_err(Error::Deserialize)
+    } else {
+        let body: ApiErrorBody = serde_json::from_str(&text).map_err(Error::Deserialize)?;
+
+        Err(Error::from((code, body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusCode;
+
+    #[test]
+    fn api_error_body_becomes_http_error_on_async_path() {
+        let body: ApiErrorBody = serde_json::from_str(r#"{
+            "message": "No matching joke found",
+            "causedBy": ["No jokes matched your provided filter(s)"],
+            "additionalInfo": "Try a different category"
+        }"#).unwrap();
+
+        match Error::from((404, body)) {
+            Error::Http { code, message, caused_by, additional_info } => {
+                assert_eq!(code, StatusCode::NotFound);
+                assert_eq!(message, "No matching joke found");
+                assert_eq!(caused_by, vec![String::from("No jokes matched your provided filter(s)")]);
+                assert_eq!(additional_info, "Try a different category");
+            },
+            other => panic!("expected Error::Http, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_value_json_single_through_async_path() {
+        let raw = r#"{
+            "id": 1,
+            "category": "Programming",
+            "type": "single",
+            "joke": "Why do programmers prefer dark mode? Because light attracts bugs.",
+            "flags": {
+                "nsfw": false,
+                "religious": false,
+                "political": false,
+                "racist": false,
+                "sexist": false,
+                "explicit": false
+            },
+            "safe": true,
+            "lang": "en"
+        }"#;
+
+        let value = parse_joke_value(raw, None).unwrap();
+
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["joke"], "Why do programmers prefer dark mode? Because light attracts bugs.");
+    }
+
+    #[test]
+    fn parse_joke_envelope_json_typed_through_async_path() {
+        let raw = r#"{
+            "id": 1,
+            "category": "Programming",
+            "type": "single",
+            "joke": "Why do programmers prefer dark mode? Because light attracts bugs.",
+            "flags": {
+                "nsfw": false,
+                "religious": false,
+                "political": false,
+                "racist": false,
+                "sexist": false,
+                "explicit": false
+            },
+            "safe": true,
+            "lang": "en"
+        }"#;
+
+        match parse_joke_envelope(raw, None).unwrap() {
+            JokeEnvelope::Single(joke) => {
+                assert_eq!(joke.id, 1);
+                assert_eq!(joke.category, Category::Programming);
+            },
+            other => panic!("expected JokeEnvelope::Single, got {other:?}"),
+        }
+    }
+}
@@ -19,11 +19,24 @@ use std::collections::HashSet;
 use std::hash::Hash;
 use std::fmt::Display;
 use ureq;
+use serde::{Deserialize, Serialize};
+use serde_yaml;
+use serde_xml_rs;
 pub use serde_json;
 
 /// The base URL for the jokeapi
 pub const BASE_URL: &'static str = "https://v2.jokeapi.dev/";
 
+/// A ceiling on `amount` used for pre-flight validation. jokeapi does not publish a hard
+/// maximum for this parameter; 10 is a conservative, undocumented client-side bound chosen to
+/// catch obviously-wrong values rather than a limit jokeapi itself enforces.
+const MAX_AMOUNT: u32 = 10;
+
+/// An async counterpart to [`Joker`], enabled with the `async` cargo feature and backed by
+/// `reqwest` instead of blocking `ureq`.
+#[cfg(feature = "async")]
+pub mod asynchronous;
+
 /// The main client struct that connects to the jokeapi
 #[derive(Debug, Clone, Hash, PartialEq)]
 pub struct Joker {
@@ -199,13 +212,19 @@ impl Joker {
     }
 
     /// This is a mostly internal function, and not needed unless you want to implement your own API call
-    /// 
+    ///
+    /// Runs pre-flight validation on whatever has been configured so far and returns
+    /// [`Error::InvalidParams`] instead of letting a known-bad combination reach jokeapi as a
+    /// confusing HTTP 400.
+    ///
     /// Basic Usage:
-    /// 
+    ///
     /// ```rust
     /// let uri_string = joker_client.build_url().unwrap();
     /// ```
-    pub fn build_url(&mut self) -> Result<String, &'static str> {
+    pub fn build_url(&mut self) -> Result<String, Error> {
+        self.validate_params()?;
+
         let mut url: String = BASE_URL.to_string();
         url.push_str("joke/");
         
@@ -313,13 +332,54 @@ impl Joker {
         Ok(url)
     }
 
+    /// Catches configuration combinations jokeapi would otherwise reject with an HTTP 400, and an
+    /// `amount` above the client-side [`MAX_AMOUNT`] bound, before a URL is ever built.
+    fn validate_params(&self) -> Result<(), Error> {
+        if self.id_range.len() == 2 && self.id_range[0] > self.id_range[1] {
+            return Err(Error::InvalidParams(format!(
+                "id range start ({}) must not be greater than end ({})",
+                self.id_range[0], self.id_range[1]
+            )));
+        }
+
+        if let Some(amount) = self.amount {
+            if amount == 0 {
+                return Err(Error::InvalidParams(String::from("amount must be greater than 0")));
+            }
+
+            if amount > MAX_AMOUNT {
+                return Err(Error::InvalidParams(format!(
+                    "amount ({amount}) exceeds the maximum of {MAX_AMOUNT} jokes per request"
+                )));
+            }
+        }
+
+        if self.search_string.is_some() && self.id_range.len() == 2 {
+            return Err(Error::InvalidParams(String::from(
+                "a search string can't be combined with an id range",
+            )));
+        }
+
+        if self.safe_mode == Some(true) && self.categories.contains(&Category::Dark) {
+            return Err(Error::InvalidParams(String::from(
+                "safe mode can't be combined with Category::Dark",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Performs the request and hands back the response body as-is, without trying to parse it.
+    /// Useful when [`set_format`](crate::Joker::set_format) is in play and you want to handle
+    /// the XML/YAML/TXT body yourself.
+    ///
     /// Basic Usage:
-    /// 
+    ///
     /// ```rust
-    /// let joke = joker_client.get_joke().unwrap();
+    /// let body = joker_client.get_joke_raw().unwrap();
     /// ```
-    pub fn get_joke(&mut self) -> Result<serde_json::Value, serde_json::Value> {
-        let url_string: String = self.build_url().unwrap();
+    pub fn get_joke_raw(&mut self) -> Result<String, Error> {
+        let url_string: String = self.build_url()?;
 
         let req;
 
@@ -330,20 +390,55 @@ impl Joker {
         }
 
         match req.call() {
-            Ok(response) => {
-                let json: serde_json::Value = response.into_json().unwrap();
+            Ok(response) => response.into_string().map_err(|_| Error::Transport),
+            Err(ureq::Error::Status(code, response)) => {
+                let body: ApiErrorBody = response.into_json().map_err(Error::DeserializeHttp)?;
 
-                Ok(json)
-            },
-            Err(ureq::Error::Status(_code, response)) => {
-                Err(response.into_json().unwrap())
+                Err(Error::from((code, body)))
             },
             Err(_) => {
-                Err(serde_json::json!({ "err": "Transport Error"}))
+                Err(Error::Transport)
             }
         }
     }
 
+    /// Basic Usage:
+    ///
+    /// ```rust
+    /// let joke = joker_client.get_joke().unwrap();
+    /// ```
+    /// This is the low-level escape hatch that hands back the raw response body as a
+    /// [`serde_json::Value`]; most callers want [`get_joke_typed`](crate::Joker::get_joke_typed)
+    /// instead. Honors whatever [`ResponseFormat`](crate::ResponseFormat) was configured with
+    /// [`set_format`](crate::Joker::set_format): YAML and XML bodies are parsed and re-expressed
+    /// as JSON, and a TXT body comes back as a plain JSON string.
+    pub fn get_joke(&mut self) -> Result<serde_json::Value, Error> {
+        let raw = self.get_joke_raw()?;
+
+        parse_joke_value(&raw, self.format)
+    }
+
+    /// Like [`get_joke`](crate::Joker::get_joke), but deserializes the response into a typed
+    /// [`Vec<Joke>`](crate::Joke) instead of a raw [`serde_json::Value`]. Both the single-joke
+    /// shape and the `amount`-batch shape are accepted and uniformly collapsed into a `Vec`.
+    /// Honors the configured [`ResponseFormat`](crate::ResponseFormat) the same way
+    /// [`get_joke`](crate::Joker::get_joke) does, except TXT has no structure to parse into a
+    /// [`Joke`](crate::Joke) and returns [`Error::UnsupportedFormat`].
+    ///
+    /// Basic Usage:
+    ///
+    /// ```rust
+    /// let jokes = joker_client.get_joke_typed().unwrap();
+    /// ```
+    pub fn get_joke_typed(&mut self) -> Result<Vec<Joke>, Error> {
+        let raw = self.get_joke_raw()?;
+
+        match parse_joke_envelope(&raw, self.format)? {
+            JokeEnvelope::Batch { jokes } => Ok(jokes),
+            JokeEnvelope::Single(joke) => Ok(vec![joke]),
+        }
+    }
+
     /// See the [official docs](https://jokeapi.dev/#submit-endpoint) to verify the format for submissions
     ///
     /// Basic Usage:
@@ -377,43 +472,47 @@ impl Joker {
     ///     }
     /// }
     /// ```
-    pub fn submit_joke(json: serde_json::Value) -> Result<serde_json::Value, serde_json::Value> {
+    pub fn submit_joke(json: serde_json::Value) -> Result<serde_json::Value, Error> {
         let mut submission_url = BASE_URL.to_string();
         submission_url.push_str("submit");
 
         match ureq::post(&submission_url).send_json(json) {
             Ok(response) => {
-                let json: serde_json::Value = response.into_json().unwrap();
+                let json: serde_json::Value = response.into_json().map_err(Error::DeserializeHttp)?;
 
                 Ok(json)
             },
-            Err(ureq::Error::Status(_code, response)) => {
-                Err(response.into_json().unwrap())
+            Err(ureq::Error::Status(code, response)) => {
+                let body: ApiErrorBody = response.into_json().map_err(Error::DeserializeHttp)?;
+
+                Err(Error::from((code, body)))
             },
             Err(_) => {
-                Err(serde_json::json!({ "err": "Transport Error" }))
+                Err(Error::Transport)
             }
         }
     }
 
     /// Usage is the same as the [submit](crate::Joker::submit_joke) function listed above, please refer to it.
-    /// 
+    ///
     /// Only difference between the two is that this function does not write anything to the API and is simply a test for verification purposes, and to avoid rate-limits for submission verification
-    pub fn submit_joke_dryrun(json: serde_json::Value) -> Result<serde_json::Value, serde_json::Value> {
+    pub fn submit_joke_dryrun(json: serde_json::Value) -> Result<serde_json::Value, Error> {
         let mut submission_url = BASE_URL.to_string();
         submission_url.push_str("submit?dry-run");
 
         match ureq::post(&submission_url).send_json(json) {
             Ok(response) => {
-                let json: serde_json::Value = response.into_json().unwrap();
+                let json: serde_json::Value = response.into_json().map_err(Error::DeserializeHttp)?;
 
                 Ok(json)
             },
-            Err(ureq::Error::Status(_code, response)) => {
-                Err(response.into_json().unwrap())
+            Err(ureq::Error::Status(code, response)) => {
+                let body: ApiErrorBody = response.into_json().map_err(Error::DeserializeHttp)?;
+
+                Err(Error::from((code, body)))
             },
             Err(_) => {
-                Err(serde_json::json!({ "err": "Transport Error" }))
+                Err(Error::Transport)
             }
         }
     }
@@ -421,7 +520,7 @@ impl Joker {
 
 // Create Joke API Parameter Types and implement string on them for ease of use in the url builder,
 // We do this instead of deriving Display for custom string conversions to conform to JokeAPI
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub enum Category {
     Any,
     Programming,
@@ -524,6 +623,269 @@ impl ToString for JokeType {
     }
 }
 
+/// The six boolean blacklist flags that JokeAPI reports back on every joke. See
+/// [`BlacklistFlag`](crate::BlacklistFlag) for the request-side equivalent.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct Flags {
+    pub nsfw: bool,
+    pub religious: bool,
+    pub political: bool,
+    pub racist: bool,
+    pub sexist: bool,
+    pub explicit: bool,
+}
+
+/// The payload of a [`Joke`](crate::Joke): either a single one-liner or a setup/delivery pair.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum JokeKind {
+    Single { joke: String },
+    TwoPart { setup: String, delivery: String },
+}
+
+impl JokeKind {
+    /// Collapses a two-part joke into `"setup\ndelivery"`. Single jokes are returned unchanged.
+    ///
+    /// Basic Usage:
+    ///
+    /// ```rust
+    /// use joketeller::JokeKind;
+    ///
+    /// let kind = JokeKind::TwoPart { setup: "setup".into(), delivery: "delivery".into() };
+    ///
+    /// assert_eq!(kind.text(), "setup\ndelivery");
+    /// ```
+    pub fn text(&self) -> String {
+        match self {
+            JokeKind::Single { joke } => joke.clone(),
+            JokeKind::TwoPart { setup, delivery } => format!("{setup}\n{delivery}"),
+        }
+    }
+}
+
+/// A single joke as returned by the jokeapi, whether it was fetched on its own or as part of
+/// an `amount`-batch. See [`Joker::get_joke_typed`](crate::Joker::get_joke_typed).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Joke {
+    pub id: u32,
+    pub category: Category,
+    pub flags: Flags,
+    pub safe: bool,
+    pub lang: String,
+    #[serde(flatten)]
+    pub kind: JokeKind,
+}
+
+/// The two shapes a `/joke` response can take: a single joke object, or an `amount`-batch
+/// carrying a `jokes` array of them. Used internally to give [`Joker::get_joke_typed`] a
+/// uniform `Vec<Joke>` regardless of which shape the API sent back.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JokeEnvelope {
+    Batch { jokes: Vec<Joke> },
+    Single(Joke),
+}
+
+/// XML-friendly mirror of [`Joke`]. `serde-xml-rs` (like most Rust XML serde backends) can't
+/// deserialize the internally-tagged, flattened [`JokeKind`] or the untagged [`JokeEnvelope`] -
+/// both rely on buffering self-describing content that non-self-describing formats like XML
+/// don't support. This sticks to plain, unambiguous fields so the XML backend has a concrete
+/// schema to match against, then [`XmlJoke::into_joke`] converts it into the real model by hand.
+#[derive(Debug, Deserialize)]
+struct XmlJoke {
+    id: u32,
+    category: Category,
+    #[serde(rename = "type")]
+    joke_type: String,
+    joke: Option<String>,
+    setup: Option<String>,
+    delivery: Option<String>,
+    flags: Flags,
+    safe: bool,
+    lang: String,
+}
+
+impl XmlJoke {
+    fn into_joke(self) -> Result<Joke, Error> {
+        let kind = match self.joke_type.as_str() {
+            "single" => JokeKind::Single {
+                joke: self.joke.ok_or_else(|| xml_error("xml joke is missing its `joke` field"))?,
+            },
+            "twopart" => JokeKind::TwoPart {
+                setup: self.setup.ok_or_else(|| xml_error("xml joke is missing its `setup` field"))?,
+                delivery: self.delivery.ok_or_else(|| xml_error("xml joke is missing its `delivery` field"))?,
+            },
+            other => return Err(xml_error(&format!("unknown joke type `{other}`"))),
+        };
+
+        Ok(Joke {
+            id: self.id,
+            category: self.category,
+            flags: self.flags,
+            safe: self.safe,
+            lang: self.lang,
+            kind,
+        })
+    }
+}
+
+/// The `<jokes>` wrapper element around repeated `<joke>` elements in an XML batch response.
+#[derive(Debug, Deserialize)]
+struct XmlJokes {
+    #[serde(rename = "joke", default)]
+    joke: Vec<XmlJoke>,
+}
+
+/// XML-friendly mirror of the batch shape of [`JokeEnvelope`].
+#[derive(Debug, Deserialize)]
+struct XmlBatch {
+    jokes: XmlJokes,
+}
+
+fn xml_error(message: &str) -> Error {
+    Error::DeserializeXml(<serde_xml_rs::Error as serde::de::Error>::custom(message))
+}
+
+/// Parses an XML response body into a [`JokeEnvelope`]. There's no untagged-enum support to
+/// lean on here, so this just tries the batch shape first and falls back to a single joke.
+fn parse_xml_envelope(raw: &str) -> Result<JokeEnvelope, Error> {
+    if let Ok(batch) = serde_xml_rs::from_str::<XmlBatch>(raw) {
+        let jokes = batch.jokes.joke.into_iter()
+            .map(XmlJoke::into_joke)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(JokeEnvelope::Batch { jokes });
+    }
+
+    let joke = serde_xml_rs::from_str::<XmlJoke>(raw).map_err(Error::DeserializeXml)?;
+
+    Ok(JokeEnvelope::Single(joke.into_joke()?))
+}
+
+/// Re-expresses a parsed XML [`JokeEnvelope`] as the same JSON shape [`parse_joke_value`]
+/// produces for JSON/YAML, rather than the garbled text-node wrapping a naive
+/// `serde_xml_rs::from_str::<serde_json::Value>()` would produce.
+fn xml_envelope_to_value(envelope: JokeEnvelope) -> Result<serde_json::Value, Error> {
+    match envelope {
+        JokeEnvelope::Single(joke) => serde_json::to_value(joke).map_err(Error::Deserialize),
+        JokeEnvelope::Batch { jokes } => {
+            let mut map = serde_json::Map::new();
+            map.insert(String::from("amount"), serde_json::Value::from(jokes.len()));
+            map.insert(String::from("jokes"), serde_json::to_value(jokes).map_err(Error::Deserialize)?);
+
+            Ok(serde_json::Value::Object(map))
+        },
+    }
+}
+
+/// Parses a raw response body into a [`serde_json::Value`] according to the configured
+/// [`ResponseFormat`], re-expressing YAML/XML as JSON and wrapping TXT as a JSON string. Shared
+/// by [`Joker::get_joke`] and its async counterpart.
+fn parse_joke_value(raw: &str, format: Option<ResponseFormat>) -> Result<serde_json::Value, Error> {
+    match format {
+        Some(ResponseFormat::Yaml) => serde_yaml::from_str(raw).map_err(Error::DeserializeYaml),
+        Some(ResponseFormat::Xml) => parse_xml_envelope(raw).and_then(xml_envelope_to_value),
+        Some(ResponseFormat::Txt) => Ok(serde_json::Value::String(raw.to_string())),
+        None => serde_json::from_str(raw).map_err(Error::Deserialize),
+    }
+}
+
+/// Parses a raw response body into a [`JokeEnvelope`] according to the configured
+/// [`ResponseFormat`]. TXT has no structure to parse into a [`Joke`] and yields
+/// [`Error::UnsupportedFormat`]. Shared by [`Joker::get_joke_typed`] and its async counterpart.
+fn parse_joke_envelope(raw: &str, format: Option<ResponseFormat>) -> Result<JokeEnvelope, Error> {
+    match format {
+        Some(ResponseFormat::Yaml) => serde_yaml::from_str(raw).map_err(Error::DeserializeYaml),
+        Some(ResponseFormat::Xml) => parse_xml_envelope(raw),
+        Some(ResponseFormat::Txt) => Err(Error::UnsupportedFormat(ResponseFormat::Txt)),
+        None => serde_json::from_str(raw).map_err(Error::Deserialize),
+    }
+}
+
+/// The error body jokeapi sends back on a non-2xx response.
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    message: String,
+    #[serde(default, rename = "causedBy")]
+    caused_by: Vec<String>,
+    #[serde(default, rename = "additionalInfo")]
+    additional_info: String,
+}
+
+/// Turns an HTTP status code and a parsed [`ApiErrorBody`] into an [`Error::Http`].
+impl From<(u16, ApiErrorBody)> for Error {
+    fn from((code, body): (u16, ApiErrorBody)) -> Error {
+        Error::Http {
+            code: StatusCode::from_http_code(code),
+            message: body.message,
+            caused_by: body.caused_by,
+            additional_info: body.additional_info,
+        }
+    }
+}
+
+/// Errors produced while building a request or talking to jokeapi. The lower-level methods
+/// such as [`Joker::get_joke`] and [`Joker::submit_joke`] return this directly; the typed
+/// [`Joker::get_joke_typed`] shares the same type.
+#[derive(Debug)]
+pub enum Error {
+    /// The request to jokeapi failed before a response was received, e.g. a network error.
+    Transport,
+    /// jokeapi responded with a non-2xx status and a structured error body.
+    Http {
+        code: StatusCode,
+        message: String,
+        caused_by: Vec<String>,
+        additional_info: String,
+    },
+    /// The response body didn't match the shape `joketeller` expected.
+    Deserialize(serde_json::Error),
+    /// `ureq` couldn't decode the response body as JSON. Distinct from [`Error::Deserialize`]
+    /// because `ureq::Response::into_json` reports failures as [`std::io::Error`] rather than
+    /// a `serde_json::Error`.
+    DeserializeHttp(std::io::Error),
+    /// The YAML response body didn't match the shape `joketeller` expected.
+    DeserializeYaml(serde_yaml::Error),
+    /// The XML response body didn't match the shape `joketeller` expected.
+    DeserializeXml(serde_xml_rs::Error),
+    /// The configured [`ResponseFormat`] has no structure to parse into a typed [`Joke`], e.g.
+    /// [`ResponseFormat::Txt`].
+    UnsupportedFormat(ResponseFormat),
+    /// The builder was configured with a combination of parameters jokeapi would reject, e.g.
+    /// a reversed id range or a search string paired with an id range.
+    InvalidParams(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Transport => write!(f, "transport error while contacting jokeapi"),
+            Error::Http { code, message, caused_by, additional_info } => {
+                write!(f, "jokeapi returned {code:?}: {message}")?;
+
+                if !caused_by.is_empty() {
+                    write!(f, " (caused by: {})", caused_by.join(", "))?;
+                }
+
+                if !additional_info.is_empty() {
+                    write!(f, " ({additional_info})")?;
+                }
+
+                Ok(())
+            },
+            Error::Deserialize(err) => write!(f, "failed to deserialize jokeapi response: {err}"),
+            Error::DeserializeHttp(err) => write!(f, "failed to decode jokeapi response body: {err}"),
+            Error::DeserializeYaml(err) => write!(f, "failed to deserialize jokeapi yaml response: {err}"),
+            Error::DeserializeXml(err) => write!(f, "failed to deserialize jokeapi xml response: {err}"),
+            Error::UnsupportedFormat(format) => write!(f, "{format:?} responses have no structure to parse into a typed Joke"),
+            Error::InvalidParams(reason) => write!(f, "invalid request parameters: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum StatusCode {
     Ok,
     Created,
@@ -535,6 +897,28 @@ pub enum StatusCode {
     TooManyRequests,
     InternalServerError,
     OriginUnreachable,
+    /// Any status jokeapi returns that isn't one of the above, carrying the raw HTTP code.
+    Unknown(u16),
+}
+
+impl StatusCode {
+    /// Maps an HTTP status code onto the jokeapi-specific subset of statuses this crate knows
+    /// about, falling back to [`StatusCode::Unknown`] for anything else.
+    fn from_http_code(code: u16) -> StatusCode {
+        match code {
+            200 => StatusCode::Ok,
+            201 => StatusCode::Created,
+            400 => StatusCode::BadRequest,
+            403 => StatusCode::Forbidden,
+            404 => StatusCode::NotFound,
+            413 => StatusCode::PayloadTooLarge,
+            414 => StatusCode::URITooLong,
+            429 => StatusCode::TooManyRequests,
+            500 => StatusCode::InternalServerError,
+            523 => StatusCode::OriginUnreachable,
+            other => StatusCode::Unknown(other),
+        }
+    }
 }
 
 fn dedup<T: Eq + Hash + Copy>(v: &mut Vec<T>) {
@@ -586,6 +970,289 @@ mod tests {
         assert_eq!(joker1.build_url().unwrap(), "https://v2.jokeapi.dev/joke/Any?idRange=2-5")
     }
 
+    #[test]
+    fn rejects_reversed_id_range() {
+        let mut joker = Joker::new();
+
+        joker.set_id_range(5, 2);
+
+        assert!(matches!(joker.build_url(), Err(Error::InvalidParams(_))));
+    }
+
+    #[test]
+    fn rejects_zero_amount() {
+        let mut joker = Joker::new();
+
+        joker.set_amount(0);
+
+        assert!(matches!(joker.build_url(), Err(Error::InvalidParams(_))));
+    }
+
+    #[test]
+    fn rejects_amount_over_max() {
+        let mut joker = Joker::new();
+
+        joker.set_amount(MAX_AMOUNT + 1);
+
+        assert!(matches!(joker.build_url(), Err(Error::InvalidParams(_))));
+    }
+
+    #[test]
+    fn rejects_search_string_with_id_range() {
+        let mut joker = Joker::new();
+
+        joker.set_search_string("chicken").set_id_range(2, 5);
+
+        assert!(matches!(joker.build_url(), Err(Error::InvalidParams(_))));
+    }
+
+    #[test]
+    fn rejects_safe_mode_with_dark_category() {
+        let mut joker = Joker::new();
+
+        joker.add_categories(&mut vec![Category::Dark]).safe_mode(true);
+
+        assert!(matches!(joker.build_url(), Err(Error::InvalidParams(_))));
+    }
+
+    const JSON_SINGLE: &str = r#"{
+        "id": 1,
+        "category": "Programming",
+        "type": "single",
+        "joke": "Why do programmers prefer dark mode? Because light attracts bugs.",
+        "flags": {
+            "nsfw": false,
+            "religious": false,
+            "political": false,
+            "racist": false,
+            "sexist": false,
+            "explicit": false
+        },
+        "safe": true,
+        "lang": "en"
+    }"#;
+
+    const JSON_BATCH: &str = r#"{
+        "amount": 2,
+        "jokes": [
+            {
+                "id": 1,
+                "category": "Programming",
+                "type": "single",
+                "joke": "Why do programmers prefer dark mode? Because light attracts bugs.",
+                "flags": {
+                    "nsfw": false,
+                    "religious": false,
+                    "political": false,
+                    "racist": false,
+                    "sexist": false,
+                    "explicit": false
+                },
+                "safe": true,
+                "lang": "en"
+            },
+            {
+                "id": 2,
+                "category": "Pun",
+                "type": "twopart",
+                "setup": "Why did the chicken cross the road?",
+                "delivery": "To get to the other side.",
+                "flags": {
+                    "nsfw": false,
+                    "religious": false,
+                    "political": false,
+                    "racist": false,
+                    "sexist": false,
+                    "explicit": false
+                },
+                "safe": true,
+                "lang": "en"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parse_joke_typed_json_single() {
+        match parse_joke_envelope(JSON_SINGLE, None).unwrap() {
+            JokeEnvelope::Single(joke) => {
+                assert_eq!(joke.id, 1);
+                assert_eq!(joke.category, Category::Programming);
+                assert_eq!(joke.kind.text(), "Why do programmers prefer dark mode? Because light attracts bugs.");
+            },
+            other => panic!("expected JokeEnvelope::Single, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_typed_json_batch() {
+        match parse_joke_envelope(JSON_BATCH, None).unwrap() {
+            JokeEnvelope::Batch { jokes } => {
+                assert_eq!(jokes.len(), 2);
+                assert_eq!(jokes[0].kind.text(), "Why do programmers prefer dark mode? Because light attracts bugs.");
+                assert_eq!(jokes[1].kind.text(), "Why did the chicken cross the road?\nTo get to the other side.");
+            },
+            other => panic!("expected JokeEnvelope::Batch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_json_single_as_json_value() {
+        let value = parse_joke_value(JSON_SINGLE, None).unwrap();
+
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["category"], "Programming");
+        assert_eq!(value["joke"], "Why do programmers prefer dark mode? Because light attracts bugs.");
+    }
+
+    const XML_SINGLE: &str = r#"
+        <data>
+            <id>1</id>
+            <category>Programming</category>
+            <type>single</type>
+            <joke>Why do programmers prefer dark mode? Because light attracts bugs.</joke>
+            <flags>
+                <nsfw>false</nsfw>
+                <religious>false</religious>
+                <political>false</political>
+                <racist>false</racist>
+                <sexist>false</sexist>
+                <explicit>false</explicit>
+            </flags>
+            <safe>true</safe>
+            <lang>en</lang>
+        </data>
+    "#;
+
+    const XML_BATCH: &str = r#"
+        <data>
+            <amount>2</amount>
+            <jokes>
+                <joke>
+                    <id>1</id>
+                    <category>Programming</category>
+                    <type>single</type>
+                    <joke>Why do programmers prefer dark mode? Because light attracts bugs.</joke>
+                    <flags>
+                        <nsfw>false</nsfw>
+                        <religious>false</religious>
+                        <political>false</political>
+                        <racist>false</racist>
+                        <sexist>false</sexist>
+                        <explicit>false</explicit>
+                    </flags>
+                    <safe>true</safe>
+                    <lang>en</lang>
+                </joke>
+                <joke>
+                    <id>2</id>
+                    <category>Pun</category>
+                    <type>twopart</type>
+                    <setup>Why did the chicken cross the road?</setup>
+                    <delivery>To get to the other side.</delivery>
+                    <flags>
+                        <nsfw>false</nsfw>
+                        <religious>false</religious>
+                        <political>false</political>
+                        <racist>false</racist>
+                        <sexist>false</sexist>
+                        <explicit>false</explicit>
+                    </flags>
+                    <safe>true</safe>
+                    <lang>en</lang>
+                </joke>
+            </jokes>
+        </data>
+    "#;
+
+    #[test]
+    fn parse_joke_typed_xml_single() {
+        match parse_joke_envelope(XML_SINGLE, Some(ResponseFormat::Xml)).unwrap() {
+            JokeEnvelope::Single(joke) => {
+                assert_eq!(joke.id, 1);
+                assert_eq!(joke.category, Category::Programming);
+                assert_eq!(joke.kind.text(), "Why do programmers prefer dark mode? Because light attracts bugs.");
+            },
+            other => panic!("expected JokeEnvelope::Single, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_typed_xml_batch() {
+        match parse_joke_envelope(XML_BATCH, Some(ResponseFormat::Xml)).unwrap() {
+            JokeEnvelope::Batch { jokes } => {
+                assert_eq!(jokes.len(), 2);
+                assert_eq!(jokes[0].kind.text(), "Why do programmers prefer dark mode? Because light attracts bugs.");
+                assert_eq!(jokes[1].kind.text(), "Why did the chicken cross the road?\nTo get to the other side.");
+            },
+            other => panic!("expected JokeEnvelope::Batch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_xml_single_as_json_value() {
+        let value = parse_joke_value(XML_SINGLE, Some(ResponseFormat::Xml)).unwrap();
+
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["category"], "Programming");
+        assert_eq!(value["joke"], "Why do programmers prefer dark mode? Because light attracts bugs.");
+    }
+
+    const YAML_SINGLE: &str = "
+id: 1
+category: Programming
+type: single
+joke: Why do programmers prefer dark mode? Because light attracts bugs.
+flags:
+  nsfw: false
+  religious: false
+  political: false
+  racist: false
+  sexist: false
+  explicit: false
+safe: true
+lang: en
+";
+
+    #[test]
+    fn parse_joke_yaml_single_as_json_value() {
+        let value = parse_joke_value(YAML_SINGLE, Some(ResponseFormat::Yaml)).unwrap();
+
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["joke"], "Why do programmers prefer dark mode? Because light attracts bugs.");
+    }
+
+    #[test]
+    fn parse_joke_typed_yaml_single() {
+        match parse_joke_envelope(YAML_SINGLE, Some(ResponseFormat::Yaml)).unwrap() {
+            JokeEnvelope::Single(joke) => {
+                assert_eq!(joke.id, 1);
+                assert_eq!(joke.category, Category::Programming);
+                assert_eq!(joke.kind.text(), "Why do programmers prefer dark mode? Because light attracts bugs.");
+            },
+            other => panic!("expected JokeEnvelope::Single, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_joke_txt_is_returned_verbatim() {
+        let body = "Why do programmers prefer dark mode? Because light attracts bugs.";
+
+        assert_eq!(
+            parse_joke_value(body, Some(ResponseFormat::Txt)).unwrap(),
+            serde_json::Value::String(body.to_string())
+        );
+    }
+
+    #[test]
+    fn parse_joke_typed_txt_is_unsupported() {
+        let body = "Why do programmers prefer dark mode? Because light attracts bugs.";
+
+        assert!(matches!(
+            parse_joke_envelope(body, Some(ResponseFormat::Txt)),
+            Err(Error::UnsupportedFormat(ResponseFormat::Txt))
+        ));
+    }
+
     #[test]
     fn getjoke() {
         let mut joker = Joker::new();
@@ -617,6 +1284,49 @@ mod tests {
         println!("{:?}", joke);
     }
 
+    #[test]
+    fn status_code_from_http_code() {
+        assert_eq!(StatusCode::from_http_code(400), StatusCode::BadRequest);
+        assert_eq!(StatusCode::from_http_code(403), StatusCode::Forbidden);
+        assert_eq!(StatusCode::from_http_code(404), StatusCode::NotFound);
+        assert_eq!(StatusCode::from_http_code(429), StatusCode::TooManyRequests);
+        assert_eq!(StatusCode::from_http_code(523), StatusCode::OriginUnreachable);
+        assert_eq!(StatusCode::from_http_code(599), StatusCode::Unknown(599));
+    }
+
+    #[test]
+    fn api_error_body_becomes_http_error() {
+        let body: ApiErrorBody = serde_json::from_str(r#"{
+            "message": "No matching joke found",
+            "causedBy": ["No jokes matched your provided filter(s)"],
+            "additionalInfo": "Try a different category"
+        }"#).unwrap();
+
+        match Error::from((404, body)) {
+            Error::Http { code, message, caused_by, additional_info } => {
+                assert_eq!(code, StatusCode::NotFound);
+                assert_eq!(message, "No matching joke found");
+                assert_eq!(caused_by, vec![String::from("No jokes matched your provided filter(s)")]);
+                assert_eq!(additional_info, "Try a different category");
+            },
+            other => panic!("expected Error::Http, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn joke_kind_text_single() {
+        let kind = JokeKind::Single { joke: String::from("why did the chicken cross the road") };
+
+        assert_eq!(kind.text(), "why did the chicken cross the road");
+    }
+
+    #[test]
+    fn joke_kind_text_twopart() {
+        let kind = JokeKind::TwoPart { setup: String::from("setup"), delivery: String::from("delivery") };
+
+        assert_eq!(kind.text(), "setup\ndelivery");
+    }
+
     #[test]
     pub fn submit_dryrun() {
         let submission = Joker::submit_joke_dryrun(serde_json::json!({